@@ -0,0 +1,130 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+use super::transform::Transform;
+
+use nalgebra::{Isometry3, Matrix4, Point3, Vector3};
+
+use std::f32::consts::PI;
+
+const MOUSE_SENSITIVITY: f32 = 0.0035;
+const ZOOM_SPEED: f32 = 0.5;
+const MIN_PITCH: f32 = -1.5;
+const MAX_PITCH: f32 = 1.5;
+const MIN_DISTANCE: f32 = 2.0;
+const MAX_DISTANCE: f32 = 50.0;
+
+/// A camera that orbits `target` from `distance` away, rotated by `yaw`/`pitch`.
+/// In single-view mode `target` stays at the origin and a player free-orbits it
+/// with the mouse; in split-screen mode each player's camera instead `follow`s
+/// their own car, so each view tracks that player's car.
+pub(crate) struct Camera {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    target: Point3<f32>,
+}
+
+impl Camera {
+    pub fn new() -> Camera {
+        Camera {
+            yaw: 0.0,
+            pitch: 0.4,
+            distance: 10.0,
+            target: Point3::origin(),
+        }
+    }
+
+    /// Applies a relative cursor movement, in pixels, to the orbit angles.
+    pub fn orbit(&mut self, dx: f32, dy: f32) {
+        self.yaw -= dx * MOUSE_SENSITIVITY;
+        self.pitch = (self.pitch - dy * MOUSE_SENSITIVITY).max(MIN_PITCH).min(MAX_PITCH);
+    }
+
+    /// Applies a scroll-wheel offset to the orbit distance.
+    pub fn zoom(&mut self, scroll_offset: f32) {
+        self.distance = (self.distance - scroll_offset * ZOOM_SPEED).max(MIN_DISTANCE).min(MAX_DISTANCE);
+    }
+
+    /// Re-centers the camera on `transform` and settles it behind the car's
+    /// current heading, so a split-screen viewport tracks that player's car.
+    pub fn follow(&mut self, transform: &Transform) {
+        self.target = Point3::from(transform.translation);
+
+        let forward = transform.rotation * Vector3::z();
+        self.yaw = forward.x.atan2(forward.z) + PI;
+    }
+
+    fn eye(&self) -> Point3<f32> {
+        let offset = Vector3::new(
+            self.distance * self.pitch.cos() * self.yaw.sin(),
+            self.distance * self.pitch.sin(),
+            self.distance * self.pitch.cos() * self.yaw.cos(),
+        );
+        self.target + offset
+    }
+
+    pub fn view_matrix(&self) -> Matrix4<f32> {
+        Isometry3::look_at_rh(&self.eye(), &self.target, &Vector3::y()).to_homogeneous()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orbit_applies_mouse_sensitivity() {
+        let mut camera = Camera::new();
+        camera.orbit(100.0, 50.0);
+        // Independently computed from the 0.0035 sensitivity constant, rather than
+        // re-deriving `Camera::orbit`'s own formula, so a sign or clamp-order bug
+        // in that formula would actually be caught. Compared with an epsilon since
+        // the literals don't bit-exactly match the f32 multiplication's rounding.
+        assert!((camera.yaw - -0.35).abs() < 1e-4);
+        assert!((camera.pitch - 0.225).abs() < 1e-4);
+    }
+
+    #[test]
+    fn orbit_clamps_pitch_to_bounds() {
+        let mut camera = Camera::new();
+        camera.orbit(0.0, 10_000.0);
+        assert_eq!(camera.pitch, MIN_PITCH);
+
+        camera.orbit(0.0, -10_000.0);
+        assert_eq!(camera.pitch, MAX_PITCH);
+    }
+
+    #[test]
+    fn zoom_clamps_distance_to_bounds() {
+        let mut camera = Camera::new();
+        camera.zoom(10_000.0);
+        assert_eq!(camera.distance, MIN_DISTANCE);
+
+        camera.zoom(-10_000.0);
+        assert_eq!(camera.distance, MAX_DISTANCE);
+    }
+
+    #[test]
+    fn follow_recenters_target_on_transform() {
+        let mut camera = Camera::new();
+        let transform = Transform {
+            translation: Vector3::new(1.0, 2.0, 3.0),
+            rotation: nalgebra::UnitQuaternion::identity(),
+        };
+
+        camera.follow(&transform);
+        assert_eq!(camera.target, Point3::new(1.0, 2.0, 3.0));
+    }
+}
@@ -0,0 +1,48 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+use super::controller::Controller;
+use super::transform::Transform;
+
+use nalgebra::{UnitQuaternion, Vector3};
+
+const ACCELERATION: f32 = 8.0;
+const DRAG: f32 = 0.98;
+const TURN_SPEED: f32 = 2.0;
+
+/// A single player's car, stepped once per fixed-timestep `update` and
+/// rendered by blending its transform with the previous step's in `Scene::draw`.
+pub(crate) struct Car {
+    pub transform: Transform,
+    speed: f32,
+}
+
+impl Car {
+    pub fn new() -> Car {
+        Car {
+            transform: Transform::identity(),
+            speed: 0.0,
+        }
+    }
+
+    pub fn update(&mut self, dt: f32, controller: &Controller) {
+        self.speed = (self.speed + controller.throttle * ACCELERATION * dt) * DRAG;
+
+        let yaw = controller.steering * TURN_SPEED * dt;
+        self.transform.rotation = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), yaw) * self.transform.rotation;
+
+        let forward = self.transform.rotation * Vector3::z();
+        self.transform.translation += forward * self.speed * dt;
+    }
+}
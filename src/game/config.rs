@@ -0,0 +1,523 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Loads the `[keybinds]` section of the user's config file and turns the key
+//! names in it into `glfw::Key`s, falling back to the hardcoded defaults
+//! whenever the file is missing or a name can't be parsed.
+
+use glfw::Key;
+use serde_derive::Deserialize;
+
+use std::fs;
+use std::path::PathBuf;
+
+const CONFIG_FILE_NAME: &str = "keybinds.toml";
+
+/// The keys a single `Controller` reacts to.
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerBindings {
+    pub accelerate: Key,
+    pub brake: Key,
+    pub left: Key,
+    pub right: Key,
+}
+
+/// All remappable keys, loaded from the `[keybinds]` section of the config file.
+#[derive(Clone, Copy, Debug)]
+pub struct Keybinds {
+    pub player_one: PlayerBindings,
+    pub player_two: PlayerBindings,
+    pub effects: [Key; 10],
+    pub quit: Key,
+    pub mouse_capture_toggle: Key,
+    pub screenshot: Key,
+}
+
+impl Default for Keybinds {
+    fn default() -> Keybinds {
+        Keybinds {
+            player_one: PlayerBindings {
+                accelerate: Key::W,
+                brake: Key::S,
+                left: Key::A,
+                right: Key::D,
+            },
+            player_two: PlayerBindings {
+                accelerate: Key::Up,
+                brake: Key::Down,
+                left: Key::Left,
+                right: Key::Right,
+            },
+            effects: [
+                Key::F1,
+                Key::F2,
+                Key::F3,
+                Key::F4,
+                Key::F5,
+                Key::F6,
+                Key::F7,
+                Key::F8,
+                Key::F9,
+                Key::F10,
+            ],
+            quit: Key::Escape,
+            mouse_capture_toggle: Key::C,
+            screenshot: Key::F12,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawPlayerBindings {
+    accelerate: String,
+    brake: String,
+    left: String,
+    right: String,
+}
+
+#[derive(Deserialize)]
+struct RawKeybinds {
+    player_one: RawPlayerBindings,
+    player_two: RawPlayerBindings,
+    effects: Vec<String>,
+    quit: String,
+    mouse_capture_toggle: String,
+    screenshot: String,
+}
+
+#[derive(Deserialize)]
+struct RawConfig {
+    keybinds: RawKeybinds,
+}
+
+impl Keybinds {
+    /// Loads the keybinds from the platform config file, writing out the
+    /// defaults on first run and falling back to them if the file is missing
+    /// or malformed.
+    pub fn load() -> Keybinds {
+        let path = config_file_path();
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => match parse(&contents) {
+                Some(keybinds) => keybinds,
+                None => {
+                    warn!("Failed to parse keybinds at {:?}, falling back to defaults", path);
+                    Keybinds::default()
+                }
+            },
+            Err(_) => {
+                let defaults = Keybinds::default();
+                write_defaults(&path);
+                defaults
+            }
+        }
+    }
+}
+
+fn parse(contents: &str) -> Option<Keybinds> {
+    let raw: RawConfig = toml::from_str(contents).ok()?;
+
+    let mut effects = Keybinds::default().effects;
+    for (slot, name) in effects.iter_mut().zip(raw.keybinds.effects.iter()) {
+        *slot = parse_key(name)?;
+    }
+
+    Some(Keybinds {
+        player_one: PlayerBindings {
+            accelerate: parse_key(&raw.keybinds.player_one.accelerate)?,
+            brake: parse_key(&raw.keybinds.player_one.brake)?,
+            left: parse_key(&raw.keybinds.player_one.left)?,
+            right: parse_key(&raw.keybinds.player_one.right)?,
+        },
+        player_two: PlayerBindings {
+            accelerate: parse_key(&raw.keybinds.player_two.accelerate)?,
+            brake: parse_key(&raw.keybinds.player_two.brake)?,
+            left: parse_key(&raw.keybinds.player_two.left)?,
+            right: parse_key(&raw.keybinds.player_two.right)?,
+        },
+        effects,
+        quit: parse_key(&raw.keybinds.quit)?,
+        mouse_capture_toggle: parse_key(&raw.keybinds.mouse_capture_toggle)?,
+        screenshot: parse_key(&raw.keybinds.screenshot)?,
+    })
+}
+
+/// Parses a config key name into a `glfw::Key`, covering the full key enum
+/// (letters, digits, punctuation, function/keypad/modifier keys) so that any
+/// physical key can be bound, not just the handful used by the defaults.
+fn parse_key(name: &str) -> Option<Key> {
+    Some(match name {
+        "Space" => Key::Space,
+        "Apostrophe" => Key::Apostrophe,
+        "Comma" => Key::Comma,
+        "Minus" => Key::Minus,
+        "Period" => Key::Period,
+        "Slash" => Key::Slash,
+        "Num0" => Key::Num0,
+        "Num1" => Key::Num1,
+        "Num2" => Key::Num2,
+        "Num3" => Key::Num3,
+        "Num4" => Key::Num4,
+        "Num5" => Key::Num5,
+        "Num6" => Key::Num6,
+        "Num7" => Key::Num7,
+        "Num8" => Key::Num8,
+        "Num9" => Key::Num9,
+        "Semicolon" => Key::Semicolon,
+        "Equal" => Key::Equal,
+        "A" => Key::A,
+        "B" => Key::B,
+        "C" => Key::C,
+        "D" => Key::D,
+        "E" => Key::E,
+        "F" => Key::F,
+        "G" => Key::G,
+        "H" => Key::H,
+        "I" => Key::I,
+        "J" => Key::J,
+        "K" => Key::K,
+        "L" => Key::L,
+        "M" => Key::M,
+        "N" => Key::N,
+        "O" => Key::O,
+        "P" => Key::P,
+        "Q" => Key::Q,
+        "R" => Key::R,
+        "S" => Key::S,
+        "T" => Key::T,
+        "U" => Key::U,
+        "V" => Key::V,
+        "W" => Key::W,
+        "X" => Key::X,
+        "Y" => Key::Y,
+        "Z" => Key::Z,
+        "LeftBracket" => Key::LeftBracket,
+        "Backslash" => Key::Backslash,
+        "RightBracket" => Key::RightBracket,
+        "GraveAccent" => Key::GraveAccent,
+        "World1" => Key::World1,
+        "World2" => Key::World2,
+        "Escape" => Key::Escape,
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Backspace" => Key::Backspace,
+        "Insert" => Key::Insert,
+        "Delete" => Key::Delete,
+        "Right" => Key::Right,
+        "Left" => Key::Left,
+        "Down" => Key::Down,
+        "Up" => Key::Up,
+        "PageUp" => Key::PageUp,
+        "PageDown" => Key::PageDown,
+        "Home" => Key::Home,
+        "End" => Key::End,
+        "CapsLock" => Key::CapsLock,
+        "ScrollLock" => Key::ScrollLock,
+        "NumLock" => Key::NumLock,
+        "PrintScreen" => Key::PrintScreen,
+        "Pause" => Key::Pause,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
+        "F13" => Key::F13,
+        "F14" => Key::F14,
+        "F15" => Key::F15,
+        "F16" => Key::F16,
+        "F17" => Key::F17,
+        "F18" => Key::F18,
+        "F19" => Key::F19,
+        "F20" => Key::F20,
+        "F21" => Key::F21,
+        "F22" => Key::F22,
+        "F23" => Key::F23,
+        "F24" => Key::F24,
+        "F25" => Key::F25,
+        "Kp0" => Key::Kp0,
+        "Kp1" => Key::Kp1,
+        "Kp2" => Key::Kp2,
+        "Kp3" => Key::Kp3,
+        "Kp4" => Key::Kp4,
+        "Kp5" => Key::Kp5,
+        "Kp6" => Key::Kp6,
+        "Kp7" => Key::Kp7,
+        "Kp8" => Key::Kp8,
+        "Kp9" => Key::Kp9,
+        "KpDecimal" => Key::KpDecimal,
+        "KpDivide" => Key::KpDivide,
+        "KpMultiply" => Key::KpMultiply,
+        "KpSubtract" => Key::KpSubtract,
+        "KpAdd" => Key::KpAdd,
+        "KpEnter" => Key::KpEnter,
+        "KpEqual" => Key::KpEqual,
+        "LeftShift" => Key::LeftShift,
+        "LeftControl" => Key::LeftControl,
+        "LeftAlt" => Key::LeftAlt,
+        "LeftSuper" => Key::LeftSuper,
+        "RightShift" => Key::RightShift,
+        "RightControl" => Key::RightControl,
+        "RightAlt" => Key::RightAlt,
+        "RightSuper" => Key::RightSuper,
+        "Menu" => Key::Menu,
+        _ => return None,
+    })
+}
+
+/// The inverse of `parse_key`, used to write out the default config file.
+/// Kept exhaustive (no wildcard arm on `Key`) so a new `glfw::Key` variant
+/// fails the build here instead of silently becoming unbindable.
+fn key_name(key: Key) -> &'static str {
+    match key {
+        Key::Space => "Space",
+        Key::Apostrophe => "Apostrophe",
+        Key::Comma => "Comma",
+        Key::Minus => "Minus",
+        Key::Period => "Period",
+        Key::Slash => "Slash",
+        Key::Num0 => "Num0",
+        Key::Num1 => "Num1",
+        Key::Num2 => "Num2",
+        Key::Num3 => "Num3",
+        Key::Num4 => "Num4",
+        Key::Num5 => "Num5",
+        Key::Num6 => "Num6",
+        Key::Num7 => "Num7",
+        Key::Num8 => "Num8",
+        Key::Num9 => "Num9",
+        Key::Semicolon => "Semicolon",
+        Key::Equal => "Equal",
+        Key::A => "A",
+        Key::B => "B",
+        Key::C => "C",
+        Key::D => "D",
+        Key::E => "E",
+        Key::F => "F",
+        Key::G => "G",
+        Key::H => "H",
+        Key::I => "I",
+        Key::J => "J",
+        Key::K => "K",
+        Key::L => "L",
+        Key::M => "M",
+        Key::N => "N",
+        Key::O => "O",
+        Key::P => "P",
+        Key::Q => "Q",
+        Key::R => "R",
+        Key::S => "S",
+        Key::T => "T",
+        Key::U => "U",
+        Key::V => "V",
+        Key::W => "W",
+        Key::X => "X",
+        Key::Y => "Y",
+        Key::Z => "Z",
+        Key::LeftBracket => "LeftBracket",
+        Key::Backslash => "Backslash",
+        Key::RightBracket => "RightBracket",
+        Key::GraveAccent => "GraveAccent",
+        Key::World1 => "World1",
+        Key::World2 => "World2",
+        Key::Escape => "Escape",
+        Key::Enter => "Enter",
+        Key::Tab => "Tab",
+        Key::Backspace => "Backspace",
+        Key::Insert => "Insert",
+        Key::Delete => "Delete",
+        Key::Right => "Right",
+        Key::Left => "Left",
+        Key::Down => "Down",
+        Key::Up => "Up",
+        Key::PageUp => "PageUp",
+        Key::PageDown => "PageDown",
+        Key::Home => "Home",
+        Key::End => "End",
+        Key::CapsLock => "CapsLock",
+        Key::ScrollLock => "ScrollLock",
+        Key::NumLock => "NumLock",
+        Key::PrintScreen => "PrintScreen",
+        Key::Pause => "Pause",
+        Key::F1 => "F1",
+        Key::F2 => "F2",
+        Key::F3 => "F3",
+        Key::F4 => "F4",
+        Key::F5 => "F5",
+        Key::F6 => "F6",
+        Key::F7 => "F7",
+        Key::F8 => "F8",
+        Key::F9 => "F9",
+        Key::F10 => "F10",
+        Key::F11 => "F11",
+        Key::F12 => "F12",
+        Key::F13 => "F13",
+        Key::F14 => "F14",
+        Key::F15 => "F15",
+        Key::F16 => "F16",
+        Key::F17 => "F17",
+        Key::F18 => "F18",
+        Key::F19 => "F19",
+        Key::F20 => "F20",
+        Key::F21 => "F21",
+        Key::F22 => "F22",
+        Key::F23 => "F23",
+        Key::F24 => "F24",
+        Key::F25 => "F25",
+        Key::Kp0 => "Kp0",
+        Key::Kp1 => "Kp1",
+        Key::Kp2 => "Kp2",
+        Key::Kp3 => "Kp3",
+        Key::Kp4 => "Kp4",
+        Key::Kp5 => "Kp5",
+        Key::Kp6 => "Kp6",
+        Key::Kp7 => "Kp7",
+        Key::Kp8 => "Kp8",
+        Key::Kp9 => "Kp9",
+        Key::KpDecimal => "KpDecimal",
+        Key::KpDivide => "KpDivide",
+        Key::KpMultiply => "KpMultiply",
+        Key::KpSubtract => "KpSubtract",
+        Key::KpAdd => "KpAdd",
+        Key::KpEnter => "KpEnter",
+        Key::KpEqual => "KpEqual",
+        Key::LeftShift => "LeftShift",
+        Key::LeftControl => "LeftControl",
+        Key::LeftAlt => "LeftAlt",
+        Key::LeftSuper => "LeftSuper",
+        Key::RightShift => "RightShift",
+        Key::RightControl => "RightControl",
+        Key::RightAlt => "RightAlt",
+        Key::RightSuper => "RightSuper",
+        Key::Menu => "Menu",
+        Key::Unknown => "Unknown",
+    }
+}
+
+fn config_file_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("carambolage")
+        .join(CONFIG_FILE_NAME)
+}
+
+fn write_defaults(path: &PathBuf) {
+    let defaults = Keybinds::default();
+    let contents = format!(
+        "[keybinds]\n\
+         player_one = {{ accelerate = \"{}\", brake = \"{}\", left = \"{}\", right = \"{}\" }}\n\
+         player_two = {{ accelerate = \"{}\", brake = \"{}\", left = \"{}\", right = \"{}\" }}\n\
+         effects = [{}]\n\
+         quit = \"{}\"\n\
+         mouse_capture_toggle = \"{}\"\n\
+         screenshot = \"{}\"\n",
+        key_name(defaults.player_one.accelerate),
+        key_name(defaults.player_one.brake),
+        key_name(defaults.player_one.left),
+        key_name(defaults.player_one.right),
+        key_name(defaults.player_two.accelerate),
+        key_name(defaults.player_two.brake),
+        key_name(defaults.player_two.left),
+        key_name(defaults.player_two.right),
+        defaults.effects.iter().map(|k| format!("\"{}\"", key_name(*k))).collect::<Vec<_>>().join(", "),
+        key_name(defaults.quit),
+        key_name(defaults.mouse_capture_toggle),
+        key_name(defaults.screenshot),
+    );
+
+    if let Some(parent) = path.parent() {
+        if fs::create_dir_all(parent).is_err() {
+            warn!("Failed to create config directory at {:?}", parent);
+            return;
+        }
+    }
+
+    if fs::write(path, contents).is_err() {
+        warn!("Failed to write default keybinds to {:?}", path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_KEY_NAMES: &[&str] = &[
+        "Space", "Apostrophe", "Comma", "Minus", "Period", "Slash", "Num0", "Num1", "Num2", "Num3", "Num4", "Num5",
+        "Num6", "Num7", "Num8", "Num9", "Semicolon", "Equal", "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K",
+        "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "LeftBracket", "Backslash",
+        "RightBracket", "GraveAccent", "World1", "World2", "Escape", "Enter", "Tab", "Backspace", "Insert", "Delete",
+        "Right", "Left", "Down", "Up", "PageUp", "PageDown", "Home", "End", "CapsLock", "ScrollLock", "NumLock",
+        "PrintScreen", "Pause", "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "F13",
+        "F14", "F15", "F16", "F17", "F18", "F19", "F20", "F21", "F22", "F23", "F24", "F25", "Kp0", "Kp1", "Kp2",
+        "Kp3", "Kp4", "Kp5", "Kp6", "Kp7", "Kp8", "Kp9", "KpDecimal", "KpDivide", "KpMultiply", "KpSubtract",
+        "KpAdd", "KpEnter", "KpEqual", "LeftShift", "LeftControl", "LeftAlt", "LeftSuper", "RightShift",
+        "RightControl", "RightAlt", "RightSuper", "Menu",
+    ];
+
+    #[test]
+    fn parse_key_round_trips_through_key_name_for_every_key() {
+        for &name in ALL_KEY_NAMES {
+            let key = parse_key(name).unwrap_or_else(|| panic!("{} should parse", name));
+            assert_eq!(key_name(key), name);
+        }
+    }
+
+    #[test]
+    fn parse_key_rejects_unknown_names() {
+        assert_eq!(parse_key("NotAKey"), None);
+        assert_eq!(parse_key(""), None);
+    }
+
+    #[test]
+    fn parse_accepts_a_fully_specified_config() {
+        let toml = r#"
+            [keybinds]
+            player_one = { accelerate = "W", brake = "S", left = "A", right = "D" }
+            player_two = { accelerate = "Up", brake = "Down", left = "Left", right = "Right" }
+            effects = ["F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10"]
+            quit = "Escape"
+            mouse_capture_toggle = "C"
+            screenshot = "F12"
+        "#;
+
+        let keybinds = parse(toml).expect("well-formed config should parse");
+        assert_eq!(keybinds.player_one.accelerate, Key::W);
+        assert_eq!(keybinds.player_two.right, Key::Right);
+        assert_eq!(keybinds.quit, Key::Escape);
+        assert_eq!(keybinds.mouse_capture_toggle, Key::C);
+        assert_eq!(keybinds.screenshot, Key::F12);
+    }
+
+    #[test]
+    fn parse_rejects_an_unbindable_key_name() {
+        let toml = r#"
+            [keybinds]
+            player_one = { accelerate = "NotAKey", brake = "S", left = "A", right = "D" }
+            player_two = { accelerate = "Up", brake = "Down", left = "Left", right = "Right" }
+            effects = ["F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10"]
+            quit = "Escape"
+            mouse_capture_toggle = "C"
+            screenshot = "F12"
+        "#;
+
+        assert!(parse(toml).is_none());
+    }
+}
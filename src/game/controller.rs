@@ -0,0 +1,67 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+use super::config::PlayerBindings;
+
+use glfw::{Action, Window};
+
+/// Turns a player's key presses into the throttle/steering inputs the scene
+/// feeds into the car physics.
+pub(crate) struct Controller {
+    active: bool,
+    bindings: PlayerBindings,
+
+    pub throttle: f32,
+    pub steering: f32,
+}
+
+impl Controller {
+    pub fn new(active: bool, bindings: PlayerBindings) -> Controller {
+        Controller {
+            active,
+            bindings,
+
+            throttle: 0.0,
+            steering: 0.0,
+        }
+    }
+
+    /// Whether this controller has a player assigned to it. Split-screen
+    /// viewports are only rendered for active controllers.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn process_input(&mut self, window: &Window, _dt: f32) {
+        if !self.active {
+            return;
+        }
+
+        self.throttle = 0.0;
+        if window.get_key(self.bindings.accelerate) == Action::Press {
+            self.throttle += 1.0;
+        }
+        if window.get_key(self.bindings.brake) == Action::Press {
+            self.throttle -= 1.0;
+        }
+
+        self.steering = 0.0;
+        if window.get_key(self.bindings.left) == Action::Press {
+            self.steering -= 1.0;
+        }
+        if window.get_key(self.bindings.right) == Action::Press {
+            self.steering += 1.0;
+        }
+    }
+}
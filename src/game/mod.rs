@@ -14,35 +14,52 @@
 // along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
 mod camera;
 mod car;
+mod config;
 mod controller;
 mod level;
 mod scene;
 mod transform;
 
-use self::controller::{Controller, ControllerLayout};
+use self::camera::Camera;
+use self::config::Keybinds;
+use self::controller::Controller;
 use self::scene::Scene;
 use grphx::{FrameBuffer, Shader};
-use util::FrameLimiter;
 
 use glfw::{Action, Context, Glfw, Key, Window};
 use nalgebra::Perspective3;
-use time::Duration;
 
 use std::cell::Cell;
+use std::fs;
 use std::mem::size_of;
 use std::os::raw::c_void;
 use std::ptr;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::mpsc::Receiver;
-use std::thread::sleep;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 type Event = Receiver<(f64, glfw::WindowEvent)>;
 
+/// Simulation step used by the fixed-timestep accumulator in `Game::run`.
+const FIXED_DT: f32 = 1.0 / 120.0;
+/// Upper bound on physics steps per frame, so a stalled frame can't spiral into
+/// running the simulation further and further behind real time.
+const MAX_CATCHUP_STEPS: u32 = 5;
+
 pub(crate) struct Game {
     // Glfw and GL
     glfw: Glfw,
     window: Window,
     events: Event,
-    frame_limiter: FrameLimiter,
+    /// Negotiated by `negotiate_context`; threaded into shader construction so
+    /// `grphx::Shader` can pick a `#version` header matching this context.
+    context_version: (u32, u32),
+    last_frame_time: f64,
+    accumulator: f32,
+    alt_enter_was_pressed: bool,
+    windowed_pos: (i32, i32),
+    windowed_size: (i32, i32),
 
     frame_buffer: FrameBuffer,
     post_proc_shader: Shader,
@@ -50,12 +67,77 @@ pub(crate) struct Game {
 
     // Game
     settings: GameSettings,
+    keybinds: Keybinds,
     scene: Scene,
     controller: Vec<Controller>,
+    camera: Camera,
+    player_cameras: Vec<Camera>,
+    mouse_captured: bool,
+    mouse_capture_toggle_was_pressed: bool,
+    last_cursor_pos: Option<(f64, f64)>,
+    screenshot_requested: bool,
+    screenshot_key_was_pressed: bool,
+}
+
+/// The three ways the game window can occupy the screen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowMode {
+    Windowed,
+    BorderlessFullscreen,
+    ExclusiveFullscreen,
+}
+
+impl WindowMode {
+    /// The mode that `Alt+Enter` switches to from this one.
+    fn next(self) -> WindowMode {
+        match self {
+            WindowMode::Windowed => WindowMode::BorderlessFullscreen,
+            WindowMode::BorderlessFullscreen => WindowMode::ExclusiveFullscreen,
+            WindowMode::ExclusiveFullscreen => WindowMode::Windowed,
+        }
+    }
+}
+
+/// How the display swap is synced to the monitor's refresh rate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapInterval {
+    Off,
+    VSync,
+    Adaptive,
+}
+
+/// How the window is divided among the active players' cameras.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SplitLayout {
+    /// One camera fills the whole window.
+    Single,
+    /// Players stacked top/bottom, split by a horizontal line.
+    Horizontal,
+    /// Players side by side, split by a vertical line.
+    Vertical,
+}
+
+/// Sub-regions (x, y, width, height) of a `width`x`height` framebuffer to
+/// render each active player's camera into, per `layout`. Pulled out of
+/// `Game::viewports` so the split math can be unit tested without a window.
+fn split_viewports(layout: SplitLayout, width: i32, height: i32) -> Vec<(i32, i32, i32, i32)> {
+    match layout {
+        SplitLayout::Single => vec![(0, 0, width, height)],
+        SplitLayout::Horizontal => {
+            let bottom_height = height / 2;
+            vec![(0, bottom_height, width, height - bottom_height), (0, 0, width, bottom_height)]
+        }
+        SplitLayout::Vertical => {
+            let left_width = width / 2;
+            vec![(0, 0, left_width, height), (left_width, 0, width - left_width, height)]
+        }
+    }
 }
 
 pub struct GameSettings {
-    pub is_fullscreen: bool,
+    pub window_mode: WindowMode,
+    pub swap_interval: SwapInterval,
+    pub split_layout: SplitLayout,
     pub width: u32,
     pub height: u32,
     pub fps: u32,
@@ -64,7 +146,9 @@ pub struct GameSettings {
 impl Default for GameSettings {
     fn default() -> GameSettings {
         GameSettings {
-            is_fullscreen: false,
+            window_mode: WindowMode::Windowed,
+            swap_interval: SwapInterval::VSync,
+            split_layout: SplitLayout::Single,
             width: 640,
             height: 480,
             fps: 60,
@@ -72,31 +156,28 @@ impl Default for GameSettings {
     }
 }
 
+/// OpenGL context hints to try, in priority order, when opening the window.
+/// We prefer a modern core context but fall back to older/compat ones for
+/// drivers that can't give us exactly what we'd like.
+const CONTEXT_HINTS: &[(u32, u32, glfw::OpenGlProfileHint)] = &[
+    (4, 3, glfw::OpenGlProfileHint::Core),
+    (3, 3, glfw::OpenGlProfileHint::Core),
+    (3, 2, glfw::OpenGlProfileHint::Compat),
+];
+
 impl Game {
-    pub(crate) fn new(settings: GameSettings) -> Game {
+    pub(crate) fn new(settings: GameSettings) -> Result<Game, String> {
         info!("Initializing game");
-        let frame_limiter = FrameLimiter::new(settings.fps);
 
         debug!("Initializing glfw window");
         let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS).unwrap();
-        glfw.window_hint(glfw::WindowHint::ContextVersion(3, 3));
-        glfw.window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfileHint::Core));
         glfw.window_hint(glfw::WindowHint::SRgbCapable(true));
         glfw.set_error_callback(Some(glfw::Callback {
             f: error_callback,
             data: Cell::new(0),
         }));
 
-        let (mut window, events) = glfw
-            .with_primary_monitor(|glfw, m| {
-                glfw.create_window(settings.width, settings.height, "Carambolage", {
-                    if settings.is_fullscreen {
-                        m.map_or(glfw::WindowMode::Windowed, |m| glfw::WindowMode::FullScreen(m))
-                    } else {
-                        glfw::WindowMode::Windowed
-                    }
-                })
-            }).expect("Failed to create GLFW window");
+        let (mut window, events, context_version) = negotiate_context(&mut glfw, &settings)?;
 
         window.make_current();
         window.set_framebuffer_size_polling(true);
@@ -104,6 +185,12 @@ impl Game {
         window.set_scroll_polling(true);
         window.set_cursor_mode(glfw::CursorMode::Normal);
 
+        glfw.set_swap_interval(match settings.swap_interval {
+            SwapInterval::Off => glfw::SwapInterval::None,
+            SwapInterval::VSync => glfw::SwapInterval::Sync(1),
+            SwapInterval::Adaptive => glfw::SwapInterval::Adaptive,
+        });
+
         debug!("Initializing openGL attributes");
         gl::load_with(|symbol| window.get_proc_address(symbol) as *const _);
         unsafe {
@@ -114,28 +201,114 @@ impl Game {
         }
 
         let frame_buffer = FrameBuffer::new(settings.width as i32, settings.height as i32);
-        let post_proc_shader = Shader::new("post_proc");
+        let post_proc_shader = Shader::new("post_proc", context_version);
 
-        let controller = vec![
-            Controller::new(true, &ControllerLayout::WASD),
-            Controller::new(true, &ControllerLayout::Arrows),
-        ];
-        let scene = Scene::new();
+        debug!("Loading keybinds");
+        let keybinds = Keybinds::load();
+        let controller = vec![Controller::new(true, keybinds.player_one), Controller::new(true, keybinds.player_two)];
+        let player_cameras = controller.iter().map(|_| Camera::new()).collect();
+        let scene = Scene::new(context_version);
+
+        let windowed_pos = window.get_pos();
+        let windowed_size = (settings.width as i32, settings.height as i32);
+
+        let last_frame_time = glfw.get_time();
 
-        Game {
+        let mut game = Game {
             glfw,
             window,
             events,
-            frame_limiter,
+            context_version,
+            last_frame_time,
+            accumulator: 0.0,
+            alt_enter_was_pressed: false,
+            windowed_pos,
+            windowed_size,
 
             frame_buffer,
             post_proc_shader,
             post_proc_effect: 0,
 
             settings,
+            keybinds,
             scene,
             controller,
+            camera: Camera::new(),
+            player_cameras,
+            mouse_captured: false,
+            mouse_capture_toggle_was_pressed: false,
+            last_cursor_pos: None,
+            screenshot_requested: false,
+            screenshot_key_was_pressed: false,
+        };
+
+        if game.settings.window_mode == WindowMode::BorderlessFullscreen || game.settings.window_mode == WindowMode::ExclusiveFullscreen {
+            let requested_mode = game.settings.window_mode;
+            game.settings.window_mode = WindowMode::Windowed;
+            game.set_window_mode(requested_mode);
         }
+
+        Ok(game)
+    }
+
+    /// Switches the window to `mode`, resizing the post-process framebuffer to match.
+    fn set_window_mode(&mut self, mode: WindowMode) {
+        if self.settings.window_mode == WindowMode::Windowed {
+            self.windowed_pos = self.window.get_pos();
+            self.windowed_size = (self.settings.width as i32, self.settings.height as i32);
+        }
+
+        match mode {
+            WindowMode::Windowed => {
+                let (x, y) = self.windowed_pos;
+                let (w, h) = self.windowed_size;
+                self.window.set_decorated(true);
+                self.window.set_monitor(glfw::WindowMode::Windowed, x, y, w as u32, h as u32, None);
+                self.settings.width = w as u32;
+                self.settings.height = h as u32;
+            }
+            WindowMode::BorderlessFullscreen => {
+                self.glfw.with_primary_monitor(|_, m| {
+                    if let Some(monitor) = m {
+                        if let Some(video_mode) = monitor.get_video_mode() {
+                            let (x, y) = monitor.get_pos();
+                            self.window.set_decorated(false);
+                            self.window
+                                .set_monitor(glfw::WindowMode::Windowed, x, y, video_mode.width, video_mode.height, Some(video_mode.refresh_rate));
+                            self.settings.width = video_mode.width;
+                            self.settings.height = video_mode.height;
+                        }
+                    }
+                });
+            }
+            WindowMode::ExclusiveFullscreen => {
+                self.glfw.with_primary_monitor(|_, m| {
+                    if let Some(monitor) = m {
+                        if let Some(video_mode) = monitor.get_video_mode() {
+                            self.window.set_decorated(true);
+                            self.window
+                                .set_monitor(glfw::WindowMode::FullScreen(&monitor), 0, 0, video_mode.width, video_mode.height, Some(video_mode.refresh_rate));
+                            self.settings.width = video_mode.width;
+                            self.settings.height = video_mode.height;
+                        }
+                    }
+                });
+            }
+        }
+
+        self.settings.window_mode = mode;
+        self.frame_buffer.resize(self.settings.width as i32, self.settings.height as i32);
+    }
+
+    /// Sub-regions (x, y, width, height) of the framebuffer to render each
+    /// active player's camera into. Driven by the number of active
+    /// controllers: a single active player always gets the whole window,
+    /// regardless of `settings.split_layout`, which only chooses the split
+    /// orientation for when more than one player is active.
+    fn viewports(&self) -> Vec<(i32, i32, i32, i32)> {
+        let active_players = self.controller.iter().filter(|c| c.is_active()).count();
+        let layout = if active_players <= 1 { SplitLayout::Single } else { self.settings.split_layout };
+        split_viewports(layout, self.settings.width as i32, self.settings.height as i32)
     }
 
     pub(crate) fn run(&mut self) {
@@ -145,8 +318,6 @@ impl Game {
         //let source = rodio::Decoder::new(BufReader::new(file)).unwrap().repeat_infinite();
         //rodio::play_raw(&device, source.convert_samples());
 
-        let nano_sec = Duration::nanoseconds(1).to_std().unwrap();
-
         let screen_vertices: [f32; 24] = [
             -1.0, 1.0, 0.0, 1.0, -1.0, -1.0, 0.0, 0.0, 1.0, -1.0, 1.0, 0.0, -1.0, 1.0, 0.0, 1.0, 1.0, -1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0,
         ];
@@ -174,13 +345,35 @@ impl Game {
         }
 
         while !self.window.should_close() {
-            let dt = self.frame_limiter.start();
+            let now = self.glfw.get_time();
+            let dt = (now - self.last_frame_time) as f32;
+            self.last_frame_time = now;
+
             self.window.make_current();
             self.glfw.poll_events();
             self.process_events();
             self.process_input(dt);
 
-            self.scene.update(dt, &self.controller);
+            self.accumulator += dt;
+            let mut catchup_steps = 0;
+            while self.accumulator >= FIXED_DT && catchup_steps < MAX_CATCHUP_STEPS {
+                self.scene.update(FIXED_DT, &self.controller);
+                self.accumulator -= FIXED_DT;
+                catchup_steps += 1;
+            }
+            if catchup_steps == MAX_CATCHUP_STEPS && self.accumulator >= FIXED_DT {
+                // The simulation fell too far behind (e.g. a stalled frame); drop the
+                // remainder instead of spiralling into ever-longer catch-up bursts.
+                self.accumulator = 0.0;
+            }
+            let alpha = self.accumulator / FIXED_DT;
+
+            for player in 0..self.player_cameras.len() {
+                let car_transform = self.scene.car_transform(player, alpha);
+                self.player_cameras[player].follow(&car_transform);
+            }
+
+            let viewports = self.viewports();
 
             unsafe {
                 self.frame_buffer.bind();
@@ -188,8 +381,23 @@ impl Game {
                 gl::ClearColor(0.2, 0.2, 0.2, 1.0);
                 gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
 
-                let projection = Perspective3::new(self.settings.width as f32 / self.settings.height as f32, 70., 0.1, 100.).unwrap();
-                self.scene.draw(&projection);
+                if viewports.len() == 1 {
+                    let projection = Perspective3::new(self.settings.width as f32 / self.settings.height as f32, 70., 0.1, 100.).unwrap();
+                    let view = self.camera.view_matrix();
+                    self.scene.draw(&projection, &view, alpha);
+                } else {
+                    gl::Enable(gl::SCISSOR_TEST);
+                    for (player, &(x, y, w, h)) in viewports.iter().enumerate() {
+                        gl::Viewport(x, y, w, h);
+                        gl::Scissor(x, y, w, h);
+
+                        let projection = Perspective3::new(w as f32 / h as f32, 70., 0.1, 100.).unwrap();
+                        let view = self.player_cameras[player].view_matrix();
+                        self.scene.draw(&projection, &view, alpha);
+                    }
+                    gl::Disable(gl::SCISSOR_TEST);
+                    gl::Viewport(0, 0, self.settings.width as i32, self.settings.height as i32);
+                }
 
                 self.frame_buffer.unbind();
 
@@ -205,15 +413,55 @@ impl Game {
                 gl::DrawArrays(gl::TRIANGLES, 0, 6);
             }
 
-            self.window.swap_buffers();
-            while self.frame_limiter.stop() {
-                self.glfw.poll_events();
-                sleep(nano_sec);
+            if self.screenshot_requested {
+                self.screenshot_requested = false;
+                self.capture_screenshot();
             }
+
+            self.window.swap_buffers();
         }
     }
 
-    #[cfg_attr(feature = "cargo-clippy", allow(single_match))]
+    /// Reads the just-rendered frame back from the default framebuffer and hands
+    /// it off to a worker thread so PNG encoding doesn't stall the render loop.
+    fn capture_screenshot(&self) {
+        let width = self.settings.width as i32;
+        let height = self.settings.height as i32;
+        let row_bytes = (width * 3) as usize;
+        let mut pixels = vec![0u8; row_bytes * height as usize];
+
+        unsafe {
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(0, 0, width, height, gl::RGB, gl::UNSIGNED_BYTE, pixels.as_mut_ptr() as *mut c_void);
+        }
+
+        thread::spawn(move || {
+            // GL's origin is bottom-left, PNG's is top-left.
+            let mut flipped = vec![0u8; pixels.len()];
+            for y in 0..height as usize {
+                let dst_row = height as usize - 1 - y;
+                flipped[dst_row * row_bytes..(dst_row + 1) * row_bytes].copy_from_slice(&pixels[y * row_bytes..(y + 1) * row_bytes]);
+            }
+
+            if let Err(err) = fs::create_dir_all("screenshots") {
+                eprintln!("Failed to create screenshots directory: {}", err);
+                return;
+            }
+
+            // Millis alone can still collide if F12 is pressed twice in the same
+            // tick, so a per-process counter is appended to guarantee a unique name.
+            static NEXT_SCREENSHOT_ID: AtomicU32 = AtomicU32::new(0);
+            let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0);
+            let id = NEXT_SCREENSHOT_ID.fetch_add(1, Ordering::Relaxed);
+            let path = format!("screenshots/screenshot-{}-{}.png", timestamp, id);
+
+            match image::save_buffer(&path, &flipped, width as u32, height as u32, image::ColorType::RGB(8)) {
+                Ok(()) => info!("Saved screenshot to {}", path),
+                Err(err) => eprintln!("Failed to save screenshot to {}: {}", path, err),
+            }
+        });
+    }
+
     pub fn process_events(&mut self) {
         for (_, event) in glfw::flush_messages(&self.events) {
             match event {
@@ -223,45 +471,54 @@ impl Game {
                     self.settings.height = height as u32;
                     self.frame_buffer.resize(width, height);
                 },
+                glfw::WindowEvent::CursorPos(x, y) => {
+                    if self.mouse_captured {
+                        if let Some((last_x, last_y)) = self.last_cursor_pos {
+                            self.camera.orbit((x - last_x) as f32, (y - last_y) as f32);
+                        }
+                        self.last_cursor_pos = Some((x, y));
+                    }
+                }
+                glfw::WindowEvent::Scroll(_, y_offset) => {
+                    self.camera.zoom(y_offset as f32);
+                }
                 _ => {}
             }
         }
     }
 
     pub fn process_input(&mut self, dt: f32) {
-        if self.window.get_key(Key::Escape) == Action::Press {
+        if self.window.get_key(self.keybinds.quit) == Action::Press {
             self.window.set_should_close(true)
         }
 
-        if self.window.get_key(Key::F1) == Action::Press {
-            self.post_proc_effect = 1;
-        }
-        if self.window.get_key(Key::F2) == Action::Press {
-            self.post_proc_effect = 2;
-        }
-        if self.window.get_key(Key::F3) == Action::Press {
-            self.post_proc_effect = 3;
-        }
-        if self.window.get_key(Key::F4) == Action::Press {
-            self.post_proc_effect = 4;
-        }
-        if self.window.get_key(Key::F5) == Action::Press {
-            self.post_proc_effect = 5;
-        }
-        if self.window.get_key(Key::F6) == Action::Press {
-            self.post_proc_effect = 6;
-        }
-        if self.window.get_key(Key::F7) == Action::Press {
-            self.post_proc_effect = 7;
+        let alt_enter_is_pressed = (self.window.get_key(Key::LeftAlt) == Action::Press || self.window.get_key(Key::RightAlt) == Action::Press)
+            && self.window.get_key(Key::Enter) == Action::Press;
+        if alt_enter_is_pressed && !self.alt_enter_was_pressed {
+            let next_mode = self.settings.window_mode.next();
+            self.set_window_mode(next_mode);
         }
-        if self.window.get_key(Key::F8) == Action::Press {
-            self.post_proc_effect = 8;
+        self.alt_enter_was_pressed = alt_enter_is_pressed;
+
+        let mouse_capture_toggle_is_pressed = self.window.get_key(self.keybinds.mouse_capture_toggle) == Action::Press;
+        if mouse_capture_toggle_is_pressed && !self.mouse_capture_toggle_was_pressed {
+            self.mouse_captured = !self.mouse_captured;
+            self.window
+                .set_cursor_mode(if self.mouse_captured { glfw::CursorMode::Disabled } else { glfw::CursorMode::Normal });
+            self.last_cursor_pos = None;
         }
-        if self.window.get_key(Key::F9) == Action::Press {
-            self.post_proc_effect = 9;
+        self.mouse_capture_toggle_was_pressed = mouse_capture_toggle_is_pressed;
+
+        let screenshot_key_is_pressed = self.window.get_key(self.keybinds.screenshot) == Action::Press;
+        if screenshot_key_is_pressed && !self.screenshot_key_was_pressed {
+            self.screenshot_requested = true;
         }
-        if self.window.get_key(Key::F10) == Action::Press {
-            self.post_proc_effect = 10;
+        self.screenshot_key_was_pressed = screenshot_key_is_pressed;
+
+        for (slot, &key) in self.keybinds.effects.iter().enumerate() {
+            if self.window.get_key(key) == Action::Press {
+                self.post_proc_effect = slot as i32 + 1;
+            }
         }
 
         for ctrl in &mut self.controller.iter_mut() {
@@ -275,3 +532,69 @@ fn error_callback(_: glfw::Error, description: String, error_count: &Cell<usize>
     println!("GLFW error {}: {}", error_count.get(), description);
     error_count.set(error_count.get() + 1);
 }
+
+/// Tries `CONTEXT_HINTS` in order, creating the window with the first context
+/// the driver accepts, instead of panicking when the ideal one isn't available.
+///
+/// Always creates a `Windowed` window at `settings.width`/`settings.height`,
+/// even when `settings.window_mode` asks for a fullscreen mode: `Game::new`
+/// switches into the real fullscreen mode right after construction via
+/// `set_window_mode`, which sizes itself off the monitor's native video mode
+/// instead of the caller's requested (and likely wrong) resolution.
+fn negotiate_context(glfw: &mut Glfw, settings: &GameSettings) -> Result<(Window, Event, (u32, u32)), String> {
+    for &(major, minor, profile) in CONTEXT_HINTS {
+        glfw.window_hint(glfw::WindowHint::ContextVersion(major, minor));
+        glfw.window_hint(glfw::WindowHint::OpenGlProfile(profile));
+
+        let created = glfw.create_window(settings.width, settings.height, "Carambolage", glfw::WindowMode::Windowed);
+
+        match created {
+            Some((window, events)) => {
+                info!("Selected OpenGL context {}.{} ({:?})", major, minor, profile);
+                return Ok((window, events, (major, minor)));
+            }
+            None => debug!("OpenGL {}.{} ({:?}) context not available, trying next", major, minor, profile),
+        }
+    }
+
+    Err("Failed to create a GLFW window with any supported OpenGL context version".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_mode_next_cycles_through_all_variants() {
+        assert_eq!(WindowMode::Windowed.next(), WindowMode::BorderlessFullscreen);
+        assert_eq!(WindowMode::BorderlessFullscreen.next(), WindowMode::ExclusiveFullscreen);
+        assert_eq!(WindowMode::ExclusiveFullscreen.next(), WindowMode::Windowed);
+    }
+
+    #[test]
+    fn single_layout_fills_the_whole_framebuffer() {
+        assert_eq!(split_viewports(SplitLayout::Single, 800, 600), vec![(0, 0, 800, 600)]);
+    }
+
+    #[test]
+    fn horizontal_layout_splits_top_and_bottom() {
+        let viewports = split_viewports(SplitLayout::Horizontal, 800, 600);
+        assert_eq!(viewports, vec![(0, 300, 800, 300), (0, 0, 800, 300)]);
+    }
+
+    #[test]
+    fn vertical_layout_splits_left_and_right() {
+        let viewports = split_viewports(SplitLayout::Vertical, 800, 600);
+        assert_eq!(viewports, vec![(0, 0, 400, 600), (400, 0, 400, 600)]);
+    }
+
+    #[test]
+    fn split_layouts_cover_odd_dimensions_without_gaps() {
+        let viewports = split_viewports(SplitLayout::Horizontal, 801, 601);
+        assert_eq!(viewports[0].1 + viewports[0].3, 601);
+        assert_eq!(viewports[1].1, 0);
+
+        let viewports = split_viewports(SplitLayout::Vertical, 801, 601);
+        assert_eq!(viewports[0].0 + viewports[0].2, viewports[1].0);
+    }
+}
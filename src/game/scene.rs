@@ -0,0 +1,123 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+use super::car::Car;
+use super::controller::Controller;
+use super::transform::Transform;
+use grphx::Shader;
+
+use nalgebra::{Matrix4, Perspective3};
+
+use std::mem::size_of;
+use std::os::raw::c_void;
+use std::ptr;
+
+/// A placeholder car body (a simple box sitting on the ground plane), shared by
+/// every `Car` since they're visually identical until real mesh assets land.
+/// Position-only, 3 floats per vertex, 12 triangles.
+#[rustfmt::skip]
+const CAR_VERTICES: [f32; 108] = [
+    -0.5, 0.0, 1.0,  0.5, 0.0, 1.0,  0.5, 0.5, 1.0,
+    -0.5, 0.0, 1.0,  0.5, 0.5, 1.0, -0.5, 0.5, 1.0,
+     0.5, 0.0, -1.0, -0.5, 0.0, -1.0, -0.5, 0.5, -1.0,
+     0.5, 0.0, -1.0, -0.5, 0.5, -1.0,  0.5, 0.5, -1.0,
+    -0.5, 0.0, -1.0, -0.5, 0.0, 1.0, -0.5, 0.5, 1.0,
+    -0.5, 0.0, -1.0, -0.5, 0.5, 1.0, -0.5, 0.5, -1.0,
+     0.5, 0.0, 1.0,  0.5, 0.0, -1.0,  0.5, 0.5, -1.0,
+     0.5, 0.0, 1.0,  0.5, 0.5, -1.0,  0.5, 0.5, 1.0,
+    -0.5, 0.5, 1.0,  0.5, 0.5, 1.0,  0.5, 0.5, -1.0,
+    -0.5, 0.5, 1.0,  0.5, 0.5, -1.0, -0.5, 0.5, -1.0,
+    -0.5, 0.0, -1.0,  0.5, 0.0, -1.0,  0.5, 0.0, 1.0,
+    -0.5, 0.0, -1.0,  0.5, 0.0, 1.0, -0.5, 0.0, 1.0,
+];
+
+pub(crate) struct Scene {
+    cars: Vec<Car>,
+    previous_transforms: Vec<Transform>,
+    car_shader: Shader,
+    car_vao: u32,
+}
+
+impl Scene {
+    /// `gl_version` is the context version `Game` negotiated at startup, so the
+    /// car shader is loaded with a `#version` header matching what the driver
+    /// actually gave us.
+    pub fn new(gl_version: (u32, u32)) -> Scene {
+        let cars = vec![Car::new(), Car::new()];
+        let previous_transforms = cars.iter().map(|car| car.transform).collect();
+
+        let mut car_vao = 0;
+        let mut car_vbo = 0;
+        unsafe {
+            gl::GenVertexArrays(1, &mut car_vao);
+            gl::BindVertexArray(car_vao);
+
+            gl::GenBuffers(1, &mut car_vbo);
+            gl::BindBuffer(gl::ARRAY_BUFFER, car_vbo);
+            gl::BufferData(
+                gl::ARRAY_BUFFER,
+                (CAR_VERTICES.len() * size_of::<f32>()) as isize,
+                &CAR_VERTICES[0] as *const f32 as *const c_void,
+                gl::STATIC_DRAW,
+            );
+
+            gl::EnableVertexAttribArray(0);
+            gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 3 * size_of::<f32>() as i32, ptr::null());
+        }
+
+        Scene {
+            cars,
+            previous_transforms,
+            car_shader: Shader::new("car", gl_version),
+            car_vao,
+        }
+    }
+
+    /// Steps every car by one fixed timestep, remembering each car's
+    /// pre-step transform so `draw` can blend into the post-step one.
+    pub fn update(&mut self, dt: f32, controllers: &[Controller]) {
+        self.previous_transforms = self.cars.iter().map(|car| car.transform).collect();
+
+        for (car, controller) in self.cars.iter_mut().zip(controllers.iter()) {
+            car.update(dt, controller);
+        }
+    }
+
+    /// The car's current transform, blended `alpha` of the way from its
+    /// transform at the start of the current fixed-timestep window.
+    pub fn car_transform(&self, index: usize, alpha: f32) -> Transform {
+        self.previous_transforms[index].lerp(&self.cars[index].transform, alpha)
+    }
+
+    pub fn draw(&self, projection: &Perspective3<f32>, view: &Matrix4<f32>, alpha: f32) {
+        self.car_shader.bind();
+
+        unsafe {
+            gl::BindVertexArray(self.car_vao);
+        }
+
+        for index in 0..self.cars.len() {
+            // Blend this car's previous and current transform by `alpha` instead of
+            // snapping straight to the fixed-timestep result, so motion stays smooth
+            // even when the render frame rate doesn't line up with FIXED_DT.
+            let model = self.car_transform(index, alpha).to_matrix();
+            let mvp = projection.to_homogeneous() * view * model;
+            self.car_shader.set_uniform_mat4(0, &mvp);
+
+            unsafe {
+                gl::DrawArrays(gl::TRIANGLES, 0, (CAR_VERTICES.len() / 3) as i32);
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+use nalgebra::{Isometry3, Matrix4, Translation3, UnitQuaternion, Vector3};
+
+/// A rigid-body position and orientation, storable per simulation step so the
+/// renderer can blend between two of them (see `Transform::lerp`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Transform {
+    pub translation: Vector3<f32>,
+    pub rotation: UnitQuaternion<f32>,
+}
+
+impl Transform {
+    pub fn identity() -> Transform {
+        Transform {
+            translation: Vector3::zeros(),
+            rotation: UnitQuaternion::identity(),
+        }
+    }
+
+    /// Linearly blends the translation and spherically blends the rotation
+    /// between `self` (`alpha` = 0) and `other` (`alpha` = 1).
+    pub fn lerp(&self, other: &Transform, alpha: f32) -> Transform {
+        Transform {
+            translation: self.translation.lerp(&other.translation, alpha),
+            rotation: self.rotation.slerp(&other.rotation, alpha),
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix4<f32> {
+        Isometry3::from_parts(Translation3::from(self.translation), self.rotation).to_homogeneous()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerp_at_zero_returns_self() {
+        let from = Transform {
+            translation: Vector3::new(1.0, 2.0, 3.0),
+            rotation: UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 1.0),
+        };
+        let to = Transform {
+            translation: Vector3::new(4.0, 5.0, 6.0),
+            rotation: UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 2.0),
+        };
+
+        assert_eq!(from.lerp(&to, 0.0), from);
+    }
+
+    #[test]
+    fn lerp_at_one_returns_other() {
+        let from = Transform::identity();
+        let to = Transform {
+            translation: Vector3::new(4.0, 5.0, 6.0),
+            rotation: UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 2.0),
+        };
+
+        assert_eq!(from.lerp(&to, 1.0), to);
+    }
+
+    #[test]
+    fn lerp_blends_translation_halfway() {
+        let from = Transform {
+            translation: Vector3::new(0.0, 0.0, 0.0),
+            rotation: UnitQuaternion::identity(),
+        };
+        let to = Transform {
+            translation: Vector3::new(2.0, 4.0, 6.0),
+            rotation: UnitQuaternion::identity(),
+        };
+
+        assert_eq!(from.lerp(&to, 0.5).translation, Vector3::new(1.0, 2.0, 3.0));
+    }
+}
@@ -0,0 +1,38 @@
+// This file is part of Carambolage.
+
+// Carambolage is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Carambolage is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Carambolage.  If not, see <http://www.gnu.org/licenses/>.
+#[macro_use]
+extern crate log;
+
+mod game;
+
+use game::{Game, GameSettings};
+
+use std::process;
+
+fn main() {
+    env_logger::init();
+
+    let settings = GameSettings::default();
+
+    let mut game = match Game::new(settings) {
+        Ok(game) => game,
+        Err(error) => {
+            error!("Failed to initialize game: {}", error);
+            process::exit(1);
+        }
+    };
+
+    game.run();
+}